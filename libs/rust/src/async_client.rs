@@ -0,0 +1,236 @@
+//! Async variant of the Cascade Hardware Monitor client.
+//!
+//! Mirrors [`crate::client::CascadeClient`] but is built on [`reqwest::Client`]
+//! so it can be embedded directly in an async runtime without spawning blocking
+//! tasks. Both clients share [`crate::request`] for URL construction and
+//! status handling, keeping them behavior-identical.
+
+use crate::error::CascadeError;
+use crate::models::*;
+use crate::request;
+use reqwest::Client;
+use serde_json::Value;
+
+/// AI-specific endpoints (async)
+pub struct AsyncAIClient<'a> {
+    client: &'a AsyncCascadeClient,
+}
+
+impl<'a> AsyncAIClient<'a> {
+    /// Get AI-friendly system status with health scores
+    pub async fn get_status(&self) -> Result<AIStatus, CascadeError> {
+        self.client.get("/ai/status").await
+    }
+
+    /// Get semantic analysis with recommendations
+    pub async fn get_analysis(&self) -> Result<AIAnalysis, CascadeError> {
+        self.client.get("/ai/analysis").await
+    }
+
+    /// Get available AI actions
+    pub async fn get_actions(&self) -> Result<Vec<AIAction>, CascadeError> {
+        let response: Value = self.client.get("/ai/actions").await?;
+        let actions = response["actions"].clone();
+        Ok(serde_json::from_value(actions)?)
+    }
+
+    /// Execute an AI action
+    pub async fn execute_action(&self, action: &str, params: Value) -> Result<ActionResult, CascadeError> {
+        self.client.post("/ai/action", serde_json::json!({
+            "action": action,
+            "params": params
+        })).await
+    }
+}
+
+/// Async Cascade Hardware Monitor API Client
+///
+/// Modern, AI-friendly hardware monitoring. Superior to OpenHardwareMonitor.
+pub struct AsyncCascadeClient {
+    base_url: String,
+    http: Client,
+}
+
+impl AsyncCascadeClient {
+    /// Create a new client
+    pub fn new(host: &str, port: u16) -> Result<Self, CascadeError> {
+        let http = Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()?;
+
+        Ok(Self {
+            base_url: format!("http://{}:{}/api/v1", host, port),
+            http,
+        })
+    }
+
+    /// Create client with default localhost:8085
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> Result<Self, CascadeError> {
+        Self::new("localhost", 8085)
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(&self, endpoint: &str) -> Result<T, CascadeError> {
+        let url = request::build_url(&self.base_url, endpoint);
+        let response = self.http.get(&url).send().await?;
+        request::check_status(response.status())?;
+        Ok(response.json().await?)
+    }
+
+    async fn post<T: serde::de::DeserializeOwned>(&self, endpoint: &str, body: Value) -> Result<T, CascadeError> {
+        let url = request::build_url(&self.base_url, endpoint);
+        let response = self.http.post(&url).json(&body).send().await?;
+        request::check_status(response.status())?;
+        Ok(response.json().await?)
+    }
+
+    /// Get AI client for AI-specific endpoints
+    pub fn ai(&self) -> AsyncAIClient<'_> {
+        AsyncAIClient { client: self }
+    }
+
+    /// Check API health
+    pub async fn health(&self) -> Result<HealthStatus, CascadeError> {
+        self.get("/health").await
+    }
+
+    /// Get full hardware snapshot
+    pub async fn get_snapshot(&self) -> Result<Snapshot, CascadeError> {
+        self.get("/snapshot").await
+    }
+
+    /// Get CPU data
+    pub async fn get_cpu(&self) -> Result<CPUData, CascadeError> {
+        self.get("/cpu").await
+    }
+
+    /// Get detailed CPU sensors
+    pub async fn get_cpu_sensors(&self) -> Result<CPUSensorData, CascadeError> {
+        self.get("/cpu/sensors").await
+    }
+
+    /// Get per-core temperatures
+    pub async fn get_cpu_temperatures(&self) -> Result<Vec<CoreTemperature>, CascadeError> {
+        self.get("/cpu/sensors/temperatures").await
+    }
+
+    /// Get CPU power data
+    pub async fn get_cpu_power(&self) -> Result<CPUPower, CascadeError> {
+        self.get("/cpu/sensors/power").await
+    }
+
+    /// Get CPU throttling status
+    pub async fn get_cpu_throttling(&self) -> Result<ThrottlingData, CascadeError> {
+        self.get("/cpu/sensors/throttling").await
+    }
+
+    /// Get GPU data
+    pub async fn get_gpu(&self) -> Result<GPUData, CascadeError> {
+        self.get("/gpu").await
+    }
+
+    /// Get all GPUs
+    pub async fn get_all_gpus(&self) -> Result<Vec<GPUData>, CascadeError> {
+        self.get("/gpu/all").await
+    }
+
+    /// Get detailed GPU sensors (NVML-style per-domain telemetry)
+    pub async fn get_gpu_sensors(&self) -> Result<GPUSensorData, CascadeError> {
+        self.get("/gpu/sensors").await
+    }
+
+    /// Get compute/graphics processes running on the GPU
+    pub async fn get_gpu_processes(&self) -> Result<Vec<GPUProcess>, CascadeError> {
+        self.get("/gpu/processes").await
+    }
+
+    /// Get memory data
+    pub async fn get_memory(&self) -> Result<MemoryData, CascadeError> {
+        self.get("/memory").await
+    }
+
+    /// Get disk data
+    pub async fn get_disks(&self) -> Result<Vec<DiskData>, CascadeError> {
+        self.get("/disks").await
+    }
+
+    /// Get SMART disk health
+    pub async fn get_smart(&self) -> Result<SMARTData, CascadeError> {
+        self.get("/smart").await
+    }
+
+    /// Get battery and power-supply status
+    pub async fn get_batteries(&self) -> Result<BatteryStatus, CascadeError> {
+        self.get("/battery").await
+    }
+
+    /// Get the primary battery, if one is present
+    pub async fn get_battery(&self) -> Result<Option<BatteryData>, CascadeError> {
+        Ok(self.get_batteries().await?.batteries.into_iter().next())
+    }
+
+    /// Get mainboard sensors
+    pub async fn get_mainboard(&self) -> Result<MainboardData, CascadeError> {
+        self.get("/mainboard").await
+    }
+
+    /// Get fan controllers
+    pub async fn get_fans(&self) -> Result<FanControllerData, CascadeError> {
+        self.get("/fans").await
+    }
+
+    /// Set fan speed
+    pub async fn set_fan_speed(&self, controller_id: &ControllerId, channel_id: &ChannelId, speed: u8) -> Result<bool, CascadeError> {
+        let result: ActionResult = self.post(
+            &format!("/fans/controllers/{}/channels/{}/speed", controller_id, channel_id),
+            serde_json::json!({"speed": speed})
+        ).await?;
+        Ok(result.success)
+    }
+
+    /// Get advanced hardware data
+    pub async fn get_advanced(&self) -> Result<AdvancedData, CascadeError> {
+        self.get("/advanced").await
+    }
+
+    /// Get inferred metrics
+    pub async fn get_inferred(&self) -> Result<InferredMetrics, CascadeError> {
+        self.get("/inferred").await
+    }
+
+    /// Get bottleneck analysis
+    pub async fn get_bottleneck(&self) -> Result<BottleneckAnalysis, CascadeError> {
+        self.get("/inferred/bottleneck").await
+    }
+
+    /// Get thermal headroom
+    pub async fn get_thermal_headroom(&self) -> Result<ThermalHeadroom, CascadeError> {
+        self.get("/inferred/thermal-headroom").await
+    }
+
+    /// Get workload profile
+    pub async fn get_workload(&self) -> Result<WorkloadProfile, CascadeError> {
+        self.get("/inferred/workload").await
+    }
+
+    /// Get unified monitor data
+    pub async fn get_monitors(&self) -> Result<UnifiedMonitorData, CascadeError> {
+        self.get("/monitors").await
+    }
+
+    /// Get all temperatures from all sources
+    pub async fn get_all_temperatures(&self) -> Result<Vec<UnifiedSensor>, CascadeError> {
+        self.get("/monitors/temperatures").await
+    }
+
+    /// Get critical sensors
+    pub async fn get_critical_sensors(&self) -> Result<Vec<UnifiedSensor>, CascadeError> {
+        self.get("/monitors/critical").await
+    }
+
+    /// Set display brightness
+    pub async fn set_brightness(&self, level: u8) -> Result<bool, CascadeError> {
+        let result: ActionResult = self.post("/ai/control/brightness", serde_json::json!({"level": level})).await?;
+        Ok(result.success)
+    }
+}
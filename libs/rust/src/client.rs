@@ -2,6 +2,7 @@
 
 use crate::error::CascadeError;
 use crate::models::*;
+use crate::request;
 use reqwest::blocking::Client;
 use serde_json::Value;
 
@@ -59,34 +60,27 @@ impl CascadeClient {
     }
 
     /// Create client with default localhost:8085
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Result<Self, CascadeError> {
         Self::new("localhost", 8085)
     }
 
     fn get<T: serde::de::DeserializeOwned>(&self, endpoint: &str) -> Result<T, CascadeError> {
-        let url = format!("{}{}", self.base_url, endpoint);
+        let url = request::build_url(&self.base_url, endpoint);
         let response = self.http.get(&url).send()?;
-        
-        if !response.status().is_success() {
-            return Err(CascadeError::Api(format!("HTTP {}", response.status())));
-        }
-        
+        request::check_status(response.status())?;
         Ok(response.json()?)
     }
 
     fn post<T: serde::de::DeserializeOwned>(&self, endpoint: &str, body: Value) -> Result<T, CascadeError> {
-        let url = format!("{}{}", self.base_url, endpoint);
+        let url = request::build_url(&self.base_url, endpoint);
         let response = self.http.post(&url).json(&body).send()?;
-        
-        if !response.status().is_success() {
-            return Err(CascadeError::Api(format!("HTTP {}", response.status())));
-        }
-        
+        request::check_status(response.status())?;
         Ok(response.json()?)
     }
 
     /// Get AI client for AI-specific endpoints
-    pub fn ai(&self) -> AIClient {
+    pub fn ai(&self) -> AIClient<'_> {
         AIClient { client: self }
     }
 
@@ -135,6 +129,16 @@ impl CascadeClient {
         self.get("/gpu/all")
     }
 
+    /// Get detailed GPU sensors (NVML-style per-domain telemetry)
+    pub fn get_gpu_sensors(&self) -> Result<GPUSensorData, CascadeError> {
+        self.get("/gpu/sensors")
+    }
+
+    /// Get compute/graphics processes running on the GPU
+    pub fn get_gpu_processes(&self) -> Result<Vec<GPUProcess>, CascadeError> {
+        self.get("/gpu/processes")
+    }
+
     /// Get memory data
     pub fn get_memory(&self) -> Result<MemoryData, CascadeError> {
         self.get("/memory")
@@ -150,6 +154,16 @@ impl CascadeClient {
         self.get("/smart")
     }
 
+    /// Get battery and power-supply status
+    pub fn get_batteries(&self) -> Result<BatteryStatus, CascadeError> {
+        self.get("/battery")
+    }
+
+    /// Get the primary battery, if one is present
+    pub fn get_battery(&self) -> Result<Option<BatteryData>, CascadeError> {
+        Ok(self.get_batteries()?.batteries.into_iter().next())
+    }
+
     /// Get mainboard sensors
     pub fn get_mainboard(&self) -> Result<MainboardData, CascadeError> {
         self.get("/mainboard")
@@ -161,7 +175,7 @@ impl CascadeClient {
     }
 
     /// Set fan speed
-    pub fn set_fan_speed(&self, controller_id: &str, channel_id: &str, speed: u8) -> Result<bool, CascadeError> {
+    pub fn set_fan_speed(&self, controller_id: &ControllerId, channel_id: &ChannelId, speed: u8) -> Result<bool, CascadeError> {
         let result: ActionResult = self.post(
             &format!("/fans/controllers/{}/channels/{}/speed", controller_id, channel_id),
             serde_json::json!({"speed": speed})
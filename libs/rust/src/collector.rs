@@ -0,0 +1,336 @@
+//! Background polling collector with in-memory time-series history.
+//!
+//! The [`CascadeClient`] only does one-shot blocking fetches. A [`Collector`]
+//! wraps a client, spawns a background thread that polls a configurable set of
+//! endpoints at a fixed interval, and stores the results in bounded per-metric
+//! ring buffers. Callers read a consistent snapshot of the history at any time
+//! via [`Collector::history`]. The history sits behind an `RwLock`: the poll
+//! thread takes the write lock only to push samples, after each fetch has
+//! finished, and a reader holds the read lock only while the history is cloned.
+
+use crate::client::CascadeClient;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A single time-stamped measurement.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Sample {
+    /// Unix timestamp in milliseconds at which the sample was taken.
+    pub timestamp: u64,
+    pub value: f64,
+}
+
+/// Rolling aggregates over the samples currently retained in a [`RingBuffer`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Aggregates {
+    pub min: f64,
+    pub max: f64,
+    pub avg: f64,
+    pub current: f64,
+    pub count: usize,
+}
+
+/// Fixed-capacity ring buffer of [`Sample`]s with O(1) push/pop.
+///
+/// A running sum is maintained so the average is O(1); the minimum and maximum
+/// are tracked incrementally and only recomputed when the evicted sample was
+/// itself the current extremum.
+#[derive(Debug, Clone)]
+pub struct RingBuffer {
+    samples: VecDeque<Sample>,
+    capacity: usize,
+    sum: f64,
+    min: f64,
+    max: f64,
+}
+
+impl RingBuffer {
+    /// Create an empty ring buffer retaining at most `capacity` samples.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+            sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Push a sample, evicting the oldest one once at capacity.
+    pub fn push(&mut self, sample: Sample) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.samples.len() == self.capacity {
+            if let Some(old) = self.samples.pop_front() {
+                self.sum -= old.value;
+                // Only the extremes need a rescan, and only when the value we
+                // just evicted was the one holding the record.
+                if old.value == self.min || old.value == self.max {
+                    self.recompute_extremes();
+                }
+            }
+        }
+        self.sum += sample.value;
+        if sample.value < self.min {
+            self.min = sample.value;
+        }
+        if sample.value > self.max {
+            self.max = sample.value;
+        }
+        self.samples.push_back(sample);
+    }
+
+    fn recompute_extremes(&mut self) {
+        self.min = f64::INFINITY;
+        self.max = f64::NEG_INFINITY;
+        for s in &self.samples {
+            if s.value < self.min {
+                self.min = s.value;
+            }
+            if s.value > self.max {
+                self.max = s.value;
+            }
+        }
+    }
+
+    /// Number of samples currently retained.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether the buffer holds no samples.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// The retained samples, oldest first.
+    pub fn samples(&self) -> Vec<Sample> {
+        self.samples.iter().copied().collect()
+    }
+
+    /// Rolling min/max/avg/current over the retained samples, or `None` when
+    /// the buffer is empty.
+    pub fn aggregates(&self) -> Option<Aggregates> {
+        let current = self.samples.back()?;
+        Some(Aggregates {
+            min: self.min,
+            max: self.max,
+            avg: self.sum / self.samples.len() as f64,
+            current: current.value,
+            count: self.samples.len(),
+        })
+    }
+}
+
+/// Which endpoints the collector polls and how much history it retains.
+#[derive(Debug, Clone)]
+pub struct CollectorConfig {
+    /// Interval between polls.
+    pub interval: Duration,
+    /// Maximum number of samples retained per metric.
+    pub capacity: usize,
+    /// Poll `/monitors/temperatures` and record every [`UnifiedSensor`].
+    pub sensors: bool,
+}
+
+impl Default for CollectorConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            capacity: 300,
+            sensors: false,
+        }
+    }
+}
+
+/// Time-series history keyed by metric.
+#[derive(Debug, Clone)]
+pub struct History {
+    /// Overall CPU load percentage.
+    pub cpu_load: RingBuffer,
+    /// CPU package temperature.
+    pub package_temperature: RingBuffer,
+    /// Primary GPU utilization percentage.
+    pub gpu_utilization: RingBuffer,
+    /// Power draw (watts).
+    pub power_draw: RingBuffer,
+    /// Per-sensor series keyed by [`UnifiedSensor`] id.
+    pub sensors: HashMap<String, RingBuffer>,
+}
+
+impl History {
+    fn new(capacity: usize) -> Self {
+        Self {
+            cpu_load: RingBuffer::new(capacity),
+            package_temperature: RingBuffer::new(capacity),
+            gpu_utilization: RingBuffer::new(capacity),
+            power_draw: RingBuffer::new(capacity),
+            sensors: HashMap::new(),
+        }
+    }
+}
+
+/// Background poller that owns a [`CascadeClient`] and maintains [`History`].
+pub struct Collector {
+    history: Arc<RwLock<History>>,
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Collector {
+    /// Start collecting, consuming the client and spawning the poll thread.
+    pub fn start(client: CascadeClient, config: CollectorConfig) -> Self {
+        let history = Arc::new(RwLock::new(History::new(config.capacity)));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let handle = {
+            let history = Arc::clone(&history);
+            let running = Arc::clone(&running);
+            thread::spawn(move || {
+                while running.load(Ordering::Relaxed) {
+                    poll_once(&client, &config, &history);
+                    thread::sleep(config.interval);
+                }
+            })
+        };
+
+        Self {
+            history,
+            running,
+            handle: Some(handle),
+        }
+    }
+
+    /// Take a snapshot of the collected history, cloned under the read lock.
+    /// Waits while the poll thread is pushing samples.
+    pub fn history(&self) -> History {
+        self.history.read().unwrap().clone()
+    }
+
+    /// Stop the poll thread and wait for it to exit.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for Collector {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Poll the configured endpoints once, pushing a sample per metric. Errors are
+/// skipped so a transient failure simply leaves a gap in the series.
+fn poll_once(client: &CascadeClient, config: &CollectorConfig, history: &RwLock<History>) {
+    let timestamp = now_millis();
+
+    if let Ok(snapshot) = client.get_snapshot() {
+        let mut hist = history.write().unwrap();
+        hist.cpu_load.push(Sample {
+            timestamp,
+            value: snapshot.cpu.load,
+        });
+        if let Some(temp) = snapshot.cpu.temperature {
+            hist.package_temperature.push(Sample {
+                timestamp,
+                value: temp,
+            });
+        }
+        if let Some(gpu) = &snapshot.gpu {
+            if let Some(util) = gpu.utilization_gpu {
+                hist.gpu_utilization.push(Sample {
+                    timestamp,
+                    value: util,
+                });
+            }
+            if let Some(power) = gpu.power_draw {
+                hist.power_draw.push(Sample {
+                    timestamp,
+                    value: power,
+                });
+            }
+        }
+    }
+
+    if config.sensors {
+        if let Ok(sensors) = client.get_all_temperatures() {
+            let mut hist = history.write().unwrap();
+            let capacity = config.capacity;
+            for sensor in sensors {
+                hist.sensors
+                    .entry(sensor.id.to_string())
+                    .or_insert_with(|| RingBuffer::new(capacity))
+                    .push(Sample {
+                        timestamp,
+                        value: sensor.value,
+                    });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(value: f64) -> Sample {
+        Sample { timestamp: 0, value }
+    }
+
+    #[test]
+    fn evicts_oldest_at_capacity() {
+        let mut buf = RingBuffer::new(3);
+        for v in [1.0, 2.0, 3.0, 4.0] {
+            buf.push(sample(v));
+        }
+        let agg = buf.aggregates().unwrap();
+        assert_eq!(buf.len(), 3);
+        assert_eq!(agg.count, 3);
+        assert_eq!(agg.current, 4.0);
+        assert_eq!(agg.min, 2.0); // 1.0 was evicted
+        assert_eq!(agg.max, 4.0);
+        assert_eq!(agg.avg, 3.0); // (2 + 3 + 4) / 3
+    }
+
+    #[test]
+    fn recomputes_max_after_evicting_it() {
+        let mut buf = RingBuffer::new(3);
+        for v in [5.0, 1.0, 2.0] {
+            buf.push(sample(v));
+        }
+        // 5.0 is both the current max and the oldest sample; the next push
+        // evicts it and must trigger a rescan for the new extremum.
+        buf.push(sample(3.0));
+        let agg = buf.aggregates().unwrap();
+        assert_eq!(agg.max, 3.0);
+        assert_eq!(agg.min, 1.0);
+        assert_eq!(agg.current, 3.0);
+        assert_eq!(agg.avg, 2.0); // (1 + 2 + 3) / 3
+    }
+
+    #[test]
+    fn empty_buffer_has_no_aggregates() {
+        let buf = RingBuffer::new(4);
+        assert!(buf.is_empty());
+        assert!(buf.aggregates().is_none());
+    }
+}
@@ -11,7 +11,7 @@
 //! - Hardware control capabilities
 //!
 //! ## Quick Start
-//! ```rust
+//! ```no_run
 //! use cascade_hardware_monitor::CascadeClient;
 //!
 //! fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -30,9 +30,16 @@
 //! ```
 
 pub mod client;
+#[cfg(feature = "async")]
+pub mod async_client;
+pub mod collector;
 pub mod models;
 pub mod error;
+pub(crate) mod request;
 
 pub use client::CascadeClient;
+#[cfg(feature = "async")]
+pub use async_client::AsyncCascadeClient;
+pub use collector::{Collector, CollectorConfig, History};
 pub use models::*;
 pub use error::CascadeError;
@@ -2,12 +2,151 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::fmt;
 
+/// Identifier of a fan controller.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ControllerId(pub String);
+
+/// Identifier of a fan channel within a controller.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ChannelId(pub String);
+
+/// Identifier of a unified sensor.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct SensorId(pub String);
+
+impl fmt::Display for ControllerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for ControllerId {
+    fn from(value: &str) -> Self {
+        ControllerId(value.to_string())
+    }
+}
+
+impl From<String> for ControllerId {
+    fn from(value: String) -> Self {
+        ControllerId(value)
+    }
+}
+
+impl fmt::Display for ChannelId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for ChannelId {
+    fn from(value: &str) -> Self {
+        ChannelId(value.to_string())
+    }
+}
+
+impl From<String> for ChannelId {
+    fn from(value: String) -> Self {
+        ChannelId(value)
+    }
+}
+
+impl fmt::Display for SensorId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for SensorId {
+    fn from(value: &str) -> Self {
+        SensorId(value.to_string())
+    }
+}
+
+impl From<String> for SensorId {
+    fn from(value: String) -> Self {
+        SensorId(value)
+    }
+}
+
+/// Deserialize a Unix timestamp (epoch seconds) into a [`DateTime<Utc>`].
+///
+/// Only compiled when the `chrono` feature is enabled; without it the
+/// corresponding fields keep their raw integer type.
+#[cfg(feature = "chrono")]
+pub fn datetime_from_unix_timestamp<'de, D>(
+    deserializer: D,
+) -> Result<chrono::DateTime<chrono::Utc>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let secs = i64::deserialize(deserializer)?;
+    chrono::DateTime::from_timestamp(secs, 0)
+        .ok_or_else(|| serde::de::Error::custom("invalid unix timestamp"))
+}
+
+/// Serialize a [`DateTime<Utc>`] back to epoch seconds, so a value read from
+/// the API re-serializes to the integer wire shape it came from.
+#[cfg(feature = "chrono")]
+pub fn unix_timestamp_from_datetime<S>(
+    value: &chrono::DateTime<chrono::Utc>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_i64(value.timestamp())
+}
+
+/// Deserialize a power-on hours count into a [`Duration`], converting the
+/// wire unit (hours) into the seconds that [`chrono::Duration`] stores.
+#[cfg(feature = "chrono")]
+pub fn duration_from_hours<'de, D>(
+    deserializer: D,
+) -> Result<Option<chrono::Duration>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let hours = Option::<u64>::deserialize(deserializer)?;
+    Ok(hours.map(|h| chrono::Duration::seconds(h as i64 * 3600)))
+}
+
+/// Serialize a power-on [`Duration`] back to whole hours for wire round-trips.
+#[cfg(feature = "chrono")]
+pub fn hours_from_duration<S>(
+    value: &Option<chrono::Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match value {
+        Some(d) => serializer.serialize_some(&(d.num_seconds() / 3600)),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg_attr(feature = "chrono", serde_with::serde_as)]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct HealthStatus {
     pub status: String,
+    #[cfg(not(feature = "chrono"))]
     pub timestamp: u64,
+    #[cfg(feature = "chrono")]
+    #[serde(
+        deserialize_with = "datetime_from_unix_timestamp",
+        serialize_with = "unix_timestamp_from_datetime"
+    )]
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    #[cfg(not(feature = "chrono"))]
     pub uptime: f64,
+    #[cfg(feature = "chrono")]
+    #[serde_as(as = "serde_with::DurationSeconds<f64>")]
+    pub uptime: chrono::Duration,
     pub version: String,
 }
 
@@ -18,6 +157,7 @@ pub struct Snapshot {
     pub memory: MemoryData,
     pub disks: Option<Vec<DiskData>>,
     pub network: Option<Value>,
+    pub battery: Option<Vec<BatteryData>>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -108,6 +248,81 @@ pub struct GPUData {
     pub fan_speed: Option<u32>,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GPUSensorData {
+    pub name: String,
+    pub vendor: Option<String>,
+    pub temperature: Option<f64>,
+    pub utilization_gpu: Option<f64>,
+    pub utilization_memory: Option<f64>,
+    pub memory_total: Option<u64>,
+    pub memory_used: Option<u64>,
+    pub power_draw: Option<f64>,
+    pub fan_speed: Option<u32>,
+    pub clocks: Option<GPUClocks>,
+    pub performance_state: Option<String>,
+    pub pcie: Option<GPUPCIe>,
+    pub encoder_utilization: Option<f64>,
+    pub decoder_utilization: Option<f64>,
+    pub ecc_errors: Option<GPUEccErrors>,
+    pub power_limit: Option<GPUPowerLimit>,
+    pub temperature_thresholds: Option<GPUTemperatureThresholds>,
+}
+
+/// Per-domain clock speeds in MHz, each paired with its maximum.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GPUClocks {
+    pub graphics: Option<u32>,
+    pub graphics_max: Option<u32>,
+    pub sm: Option<u32>,
+    pub sm_max: Option<u32>,
+    pub memory: Option<u32>,
+    pub memory_max: Option<u32>,
+    pub video: Option<u32>,
+    pub video_max: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GPUPCIe {
+    pub tx_throughput: Option<u64>,
+    pub rx_throughput: Option<u64>,
+    pub link_gen: Option<u32>,
+    pub link_width: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GPUEccErrors {
+    pub volatile: Option<u64>,
+    pub aggregate: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GPUPowerLimit {
+    pub enforced: Option<f64>,
+    pub default: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GPUTemperatureThresholds {
+    pub slowdown: Option<f64>,
+    pub shutdown: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GPUProcess {
+    pub pid: u32,
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub process_type: String,
+    pub used_memory: Option<u64>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MemoryData {
@@ -145,7 +360,14 @@ pub struct SMARTDisk {
     pub model: String,
     pub health_status: String,
     pub temperature: Option<f64>,
+    #[cfg(not(feature = "chrono"))]
     pub power_on_hours: Option<u64>,
+    #[cfg(feature = "chrono")]
+    #[serde(
+        deserialize_with = "duration_from_hours",
+        serialize_with = "hours_from_duration"
+    )]
+    pub power_on_hours: Option<chrono::Duration>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -156,6 +378,30 @@ pub struct HealthySummary {
     pub failing: u32,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatteryStatus {
+    pub available: bool,
+    pub batteries: Vec<BatteryData>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatteryData {
+    pub name: String,
+    pub state_of_charge: f64,
+    pub state: String,
+    pub voltage: Option<f64>,
+    pub current: Option<f64>,
+    pub power: Option<f64>,
+    pub design_capacity: Option<f64>,
+    pub full_charge_capacity: Option<f64>,
+    pub cycle_count: Option<u32>,
+    pub health: Option<f64>,
+    pub time_to_empty: Option<f64>,
+    pub time_to_full: Option<f64>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MainboardData {
@@ -216,7 +462,7 @@ pub struct FanControllerData {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct FanController {
-    pub id: String,
+    pub id: ControllerId,
     pub name: String,
     pub channels: Vec<FanChannel>,
 }
@@ -224,7 +470,7 @@ pub struct FanController {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FanChannel {
-    pub id: String,
+    pub id: ChannelId,
     pub name: String,
     pub speed_percent: u32,
     pub rpm: Option<u32>,
@@ -332,7 +578,7 @@ pub struct MonitorSources {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct UnifiedSensor {
-    pub id: String,
+    pub id: SensorId,
     pub name: String,
     #[serde(rename = "type")]
     pub sensor_type: String,
@@ -344,7 +590,14 @@ pub struct UnifiedSensor {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct AIStatus {
+    #[cfg(not(feature = "chrono"))]
     pub timestamp: u64,
+    #[cfg(feature = "chrono")]
+    #[serde(
+        deserialize_with = "datetime_from_unix_timestamp",
+        serialize_with = "unix_timestamp_from_datetime"
+    )]
+    pub timestamp: chrono::DateTime<chrono::Utc>,
     pub system: SystemHealth,
     pub summary: Value,
     pub capabilities: Value,
@@ -377,3 +630,28 @@ pub struct ActionResult {
     pub success: bool,
     pub message: Option<String>,
 }
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_on_hours_round_trips_through_seconds() {
+        let json = r#"{"device":"/dev/sda","model":"X","healthStatus":"ok","temperature":null,"powerOnHours":3}"#;
+        let disk: SMARTDisk = serde_json::from_str(json).unwrap();
+        assert_eq!(disk.power_on_hours, Some(chrono::Duration::seconds(10800)));
+
+        let back = serde_json::to_value(&disk).unwrap();
+        assert_eq!(back["powerOnHours"], serde_json::json!(3));
+    }
+
+    #[test]
+    fn timestamp_round_trips_through_epoch_seconds() {
+        let json = r#"{"status":"ok","timestamp":1700000000,"uptime":3600.0,"version":"1.0"}"#;
+        let health: HealthStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(health.timestamp.timestamp(), 1_700_000_000);
+
+        let back = serde_json::to_value(&health).unwrap();
+        assert_eq!(back["timestamp"], serde_json::json!(1_700_000_000));
+    }
+}
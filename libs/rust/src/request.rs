@@ -0,0 +1,22 @@
+//! Shared request plumbing used by both the blocking and async clients.
+//!
+//! Keeping URL construction and status-code handling in one place ensures the
+//! two client variants stay behavior-identical; a bugfix here applies to both.
+
+use crate::error::CascadeError;
+use reqwest::StatusCode;
+
+/// Build a full endpoint URL from a client's base URL.
+pub(crate) fn build_url(base_url: &str, endpoint: &str) -> String {
+    format!("{}{}", base_url, endpoint)
+}
+
+/// Map a non-success HTTP status onto [`CascadeError::Api`], returning `Ok(())`
+/// when the request succeeded.
+pub(crate) fn check_status(status: StatusCode) -> Result<(), CascadeError> {
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(CascadeError::Api(format!("HTTP {}", status)))
+    }
+}